@@ -0,0 +1,231 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, IndicatorResult, PeriodType, ValueType, OHLCV};
+use crate::methods::ATR;
+
+/// A concrete, risk-normalized trading plan derived from a raw indicator signal.
+///
+/// Produced by a [`PositionSizer`] out of an [`IndicatorResult`] and the candle it was
+/// computed on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TradePlan {
+	/// Planned entry price.
+	pub entry: ValueType,
+	/// Price at which the position is closed for a loss.
+	pub stop_loss: ValueType,
+	/// Price at which the position is closed for a profit.
+	pub take_profit: ValueType,
+	/// Position size, scaled so that the capital at risk is the same for every trade
+	/// regardless of how far away the stop-loss is.
+	pub size: ValueType,
+}
+
+/// Turns the raw [`Action`]s inside an [`IndicatorResult`] into a [`TradePlan`].
+///
+/// Implementors decide how far to place the stop-loss/take-profit levels and how to size
+/// the resulting position. This lets any indicator that returns an [`IndicatorResult`]
+/// (e.g. [`KlingerVolumeOscillator`](crate::indicators::KlingerVolumeOscillator) or
+/// [`Envelopes`](crate::indicators::Envelopes)) be turned into actionable, risk-normalized
+/// orders without hand-rolling the stop/target/size math for every signal.
+pub trait PositionSizer {
+	/// Consumes the next candle and the signal computed for it.
+	///
+	/// Returns `Some(TradePlan)` only when a signal slot carries a full buy or sell
+	/// [`Action`], `None` otherwise. An implementor may also return `None` for a full signal
+	/// when it cannot place the stop-loss for this candle (see [`ATRPositionSizer`]).
+	fn next<T: OHLCV>(&mut self, candle: &T, result: &IndicatorResult) -> Option<TradePlan>;
+}
+
+/// Config for the ATR-multiple [`PositionSizer`].
+///
+/// On a full buy/sell [`Action`] coming from any signal slot of the wrapped indicator,
+/// places the stop-loss and take-profit a multiple of the current
+/// [`ATR`](crate::methods::ATR) away from the entry price, then sizes the position so
+/// that `risk_capital` is lost if the stop-loss is hit:
+///
+/// `size = risk_capital / |entry - stop_loss|`
+///
+/// When the current ATR is not positive, no plan is produced (`None`), even on a full signal.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/a/atr.asp>
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ATRPositionSizer {
+	/// ATR period. Default is `14`.
+	///
+	/// Period range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub atr_period: PeriodType,
+	/// Stop-loss distance from entry, as a multiple of ATR. Default is `2.0`.
+	///
+	/// Range in (`0.0`; `+inf`).
+	pub k_sl: ValueType,
+	/// Take-profit distance from entry, as a multiple of ATR. Default is `3.0`.
+	///
+	/// Range in (`0.0`; `+inf`).
+	pub k_tp: ValueType,
+	/// Capital risked on a single trade, in quote currency. Default is `1.0`.
+	///
+	/// Range in (`0.0`; `+inf`).
+	pub risk_capital: ValueType,
+}
+
+impl ATRPositionSizer {
+	/// Validates the config and seeds an [`ATRPositionSizerInstance`] on `candle`.
+	pub fn init<T: OHLCV>(self, candle: &T) -> Result<ATRPositionSizerInstance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		Ok(ATRPositionSizerInstance {
+			atr: ATR::new(cfg.atr_period, candle)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.atr_period > 1 && self.k_sl > 0.0 && self.k_tp > 0.0 && self.risk_capital > 0.0
+	}
+}
+
+impl Default for ATRPositionSizer {
+	fn default() -> Self {
+		Self {
+			atr_period: 14,
+			k_sl: 2.0,
+			k_tp: 3.0,
+			risk_capital: 1.0,
+		}
+	}
+}
+
+/// Instance of the ATR-multiple [`PositionSizer`]. See [`ATRPositionSizer`] for the config.
+#[derive(Debug, Clone)]
+pub struct ATRPositionSizerInstance {
+	cfg: ATRPositionSizer,
+	atr: ATR,
+}
+
+impl PositionSizer for ATRPositionSizerInstance {
+	fn next<T: OHLCV>(&mut self, candle: &T, result: &IndicatorResult) -> Option<TradePlan> {
+		let atr = self.atr.next(candle);
+
+		if atr <= 0.0 {
+			return None;
+		}
+
+		let direction = result.signals().iter().find_map(|signal| match signal {
+			Action::Buy(v) if *v >= 1.0 => Some(1.0),
+			Action::Sell(v) if *v >= 1.0 => Some(-1.0),
+			_ => None,
+		})?;
+
+		let entry = candle.close();
+		let stop_loss = entry - direction * self.cfg.k_sl * atr;
+		let take_profit = entry + direction * self.cfg.k_tp * atr;
+		let size = self.cfg.risk_capital / (entry - stop_loss).abs();
+
+		Some(TradePlan {
+			entry,
+			stop_loss,
+			take_profit,
+			size,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(Debug, Clone, Copy, Default)]
+	struct TestCandle {
+		open: ValueType,
+		high: ValueType,
+		low: ValueType,
+		close: ValueType,
+		volume: ValueType,
+	}
+
+	impl OHLCV for TestCandle {
+		fn open(&self) -> ValueType {
+			self.open
+		}
+		fn high(&self) -> ValueType {
+			self.high
+		}
+		fn low(&self) -> ValueType {
+			self.low
+		}
+		fn close(&self) -> ValueType {
+			self.close
+		}
+		fn volume(&self) -> ValueType {
+			self.volume
+		}
+	}
+
+	fn flat_candle(high: ValueType, low: ValueType, close: ValueType) -> TestCandle {
+		TestCandle {
+			open: close,
+			high,
+			low,
+			close,
+			volume: 1.0,
+		}
+	}
+
+	#[test]
+	fn buy_signal_produces_trade_plan() {
+		let seed = flat_candle(110.0, 100.0, 105.0);
+		let mut sizer = ATRPositionSizer::default().init(&seed).unwrap();
+
+		let candle = flat_candle(110.0, 100.0, 105.0);
+		let result = IndicatorResult::new(&[0.0], &[Action::Buy(1.0)]);
+		let plan = sizer.next(&candle, &result).unwrap();
+
+		assert_eq!(plan.entry, 105.0);
+		assert_eq!(plan.stop_loss, 85.0);
+		assert_eq!(plan.take_profit, 135.0);
+		assert_eq!(plan.size, 1.0 / 20.0);
+	}
+
+	#[test]
+	fn sell_signal_flips_stop_and_target() {
+		let seed = flat_candle(110.0, 100.0, 105.0);
+		let mut sizer = ATRPositionSizer::default().init(&seed).unwrap();
+
+		let candle = flat_candle(110.0, 100.0, 105.0);
+		let result = IndicatorResult::new(&[0.0], &[Action::Sell(1.0)]);
+		let plan = sizer.next(&candle, &result).unwrap();
+
+		assert_eq!(plan.entry, 105.0);
+		assert_eq!(plan.stop_loss, 125.0);
+		assert_eq!(plan.take_profit, 75.0);
+		assert_eq!(plan.size, 1.0 / 20.0);
+	}
+
+	#[test]
+	fn no_full_signal_returns_none() {
+		let seed = flat_candle(110.0, 100.0, 105.0);
+		let mut sizer = ATRPositionSizer::default().init(&seed).unwrap();
+
+		let candle = flat_candle(110.0, 100.0, 105.0);
+		let result = IndicatorResult::new(&[0.0], &[Action::None]);
+		assert!(sizer.next(&candle, &result).is_none());
+	}
+
+	#[test]
+	fn zero_atr_returns_none() {
+		let seed = flat_candle(100.0, 100.0, 100.0);
+		let mut sizer = ATRPositionSizer::default().init(&seed).unwrap();
+
+		let candle = flat_candle(100.0, 100.0, 100.0);
+		let result = IndicatorResult::new(&[0.0], &[Action::Buy(1.0)]);
+		assert!(sizer.next(&candle, &result).is_none());
+	}
+}
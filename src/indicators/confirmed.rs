@@ -0,0 +1,257 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, IndicatorConfig, IndicatorInstance, IndicatorResult, OHLCV};
+
+/// Config for the [`Confirmed`] signal-debouncing wrapper.
+///
+/// Wraps any [`IndicatorConfig`] `C`, forwards its value outputs unchanged, and replaces
+/// its (often whipsaw-prone) action outputs with confirmed ones: once a signal slot turns
+/// a full buy/sell, that direction must persist for `k` consecutive candles (a slot that
+/// reverts to [`Action::None`] does not break the streak — only an opposite full signal
+/// does) before it is forwarded once. Useful for indicators like
+/// [`KlingerVolumeOscillator`](crate::indicators::KlingerVolumeOscillator) (zero-line
+/// crosses) or [`Envelopes`](crate::indicators::Envelopes) (band touches), whose raw
+/// signal only fires on the crossing candle itself and would otherwise never repeat.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConfirmedConfig<C: IndicatorConfig> {
+	/// Wrapped indicator's config.
+	pub inner: C,
+	/// Number of consecutive candles a direction must persist before it is confirmed.
+	///
+	/// Range in \[`1`; `+inf`\).
+	pub k: u8,
+}
+
+impl<C: IndicatorConfig + Clone> IndicatorConfig for ConfirmedConfig<C> {
+	type Instance = Confirmed<C>;
+
+	const NAME: &'static str = "Confirmed";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let signals = self.inner.size().1 as usize;
+		let inner = self.inner.clone().init(candle)?;
+
+		Ok(Confirmed {
+			cfg: self,
+			inner,
+			streak: vec![(Action::None, 0, false); signals],
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.k > 0 && self.inner.validate()
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		if name == "k" {
+			return match value.parse() {
+				Err(_) => Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => {
+					self.k = value;
+					Ok(())
+				}
+			};
+		}
+
+		self.inner.set(name, value)
+	}
+
+	fn size(&self) -> (u8, u8) {
+		self.inner.size()
+	}
+}
+
+/// Instance of the [`Confirmed`] signal-debouncing wrapper. See [`ConfirmedConfig`] for the
+/// config.
+#[derive(Debug, Clone)]
+pub struct Confirmed<C: IndicatorConfig> {
+	cfg: ConfirmedConfig<C>,
+	inner: C::Instance,
+	/// Per-slot `(direction, streak length since last reversal, already forwarded)`.
+	streak: Vec<(Action, u8, bool)>,
+}
+
+impl<C: IndicatorConfig> IndicatorInstance for Confirmed<C> {
+	type Config = ConfirmedConfig<C>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let raw = self.inner.next(candle);
+
+		let signals: Vec<Action> = raw
+			.signals()
+			.iter()
+			.zip(self.streak.iter_mut())
+			.map(|(&signal, slot)| {
+				// Only full buy/sell signals count; weaker ones are treated like `None`.
+				let signal = match signal {
+					Action::Buy(v) if v >= 1.0 => signal,
+					Action::Sell(v) if v >= 1.0 => signal,
+					_ => Action::None,
+				};
+
+				if signal != Action::None && signal != slot.0 {
+					// A genuine reversal: a different, non-`None` direction appeared.
+					*slot = (signal, 1, false);
+				} else if slot.0 != Action::None && !slot.2 {
+					// Either the same direction fired again, or the raw signal fell back
+					// to `None` — in both cases the established regime persists. Once
+					// confirmed there is nothing left to count towards, so stop (a regime
+					// can persist for far longer than `u8::MAX` candles).
+					slot.1 = slot.1.saturating_add(1);
+				}
+
+				if !slot.2 && slot.0 != Action::None && slot.1 >= self.cfg.k {
+					slot.2 = true;
+					slot.0
+				} else {
+					Action::None
+				}
+			})
+			.collect();
+
+		IndicatorResult::new(raw.values(), &signals)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::core::ValueType;
+
+	#[derive(Debug, Clone, Copy, Default)]
+	struct TestCandle;
+
+	impl OHLCV for TestCandle {
+		fn open(&self) -> ValueType {
+			0.0
+		}
+		fn high(&self) -> ValueType {
+			0.0
+		}
+		fn low(&self) -> ValueType {
+			0.0
+		}
+		fn close(&self) -> ValueType {
+			0.0
+		}
+		fn volume(&self) -> ValueType {
+			0.0
+		}
+	}
+
+	// Replays a fixed script of single-slot raw signals, ignoring the candle — stands in
+	// for a real indicator whose `Action` only fires on the crossing candle, like
+	// `KlingerVolumeOscillator`'s zero-line cross.
+	#[derive(Debug, Clone)]
+	struct ScriptedConfig {
+		actions: Vec<Action>,
+	}
+
+	impl IndicatorConfig for ScriptedConfig {
+		type Instance = ScriptedInstance;
+
+		const NAME: &'static str = "Scripted";
+
+		fn init<T: OHLCV>(self, _candle: &T) -> Result<Self::Instance, Error> {
+			Ok(ScriptedInstance {
+				cfg: self,
+				index: 0,
+			})
+		}
+
+		fn validate(&self) -> bool {
+			true
+		}
+
+		fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+			Err(Error::ParameterParse(name.to_string(), value))
+		}
+
+		fn size(&self) -> (u8, u8) {
+			(0, 1)
+		}
+	}
+
+	#[derive(Debug, Clone)]
+	struct ScriptedInstance {
+		cfg: ScriptedConfig,
+		index: usize,
+	}
+
+	impl IndicatorInstance for ScriptedInstance {
+		type Config = ScriptedConfig;
+
+		fn config(&self) -> &Self::Config {
+			&self.cfg
+		}
+
+		fn next<T: OHLCV>(&mut self, _candle: &T) -> IndicatorResult {
+			let action = self.cfg.actions[self.index];
+			self.index += 1;
+			IndicatorResult::new(&[], &[action])
+		}
+	}
+
+	fn run(actions: Vec<Action>, k: u8) -> Vec<Action> {
+		let cfg = ConfirmedConfig {
+			inner: ScriptedConfig {
+				actions: actions.clone(),
+			},
+			k,
+		};
+		let mut confirmed = cfg.init(&TestCandle).unwrap();
+
+		(0..actions.len())
+			.map(|_| confirmed.next(&TestCandle).signals()[0])
+			.collect()
+	}
+
+	#[test]
+	fn one_shot_cross_signal_confirms_once_it_persists() {
+		let actions = vec![Action::Buy(1.0), Action::None, Action::None, Action::None];
+
+		assert_eq!(
+			run(actions, 3),
+			vec![Action::None, Action::None, Action::Buy(1.0), Action::None]
+		);
+	}
+
+	#[test]
+	fn reversal_before_confirmation_resets_the_streak() {
+		let actions = vec![
+			Action::Buy(1.0),
+			Action::None,
+			Action::Sell(1.0),
+			Action::None,
+			Action::None,
+		];
+
+		assert_eq!(
+			run(actions, 3),
+			vec![
+				Action::None,
+				Action::None,
+				Action::None,
+				Action::None,
+				Action::Sell(1.0),
+			]
+		);
+	}
+
+	#[test]
+	fn repeated_same_direction_signal_confirms_after_k_candles() {
+		let actions = vec![Action::Buy(1.0), Action::Buy(1.0)];
+
+		assert_eq!(run(actions, 2), vec![Action::None, Action::Buy(1.0)]);
+	}
+}
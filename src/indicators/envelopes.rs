@@ -1,15 +1,24 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Action, Error, Method, MovingAverageConstructor, Source, ValueType, OHLCV};
+use crate::core::{
+	Action, Error, Method, MovingAverageConstructor, PeriodType, Source, ValueType, OHLCV,
+};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::MA;
+use crate::methods::ATR;
 
 /// Envelopes
 ///
+/// In `adaptive` mode the bands are volatility-driven (Keltner-style): instead of
+/// `ma * (1.0 ± k)`, the bounds are `ma + k_upper * vol` and `ma - k_lower * vol`, where
+/// `vol` is an [`ATR`](crate::methods::ATR) estimate. This lets `k_upper`/`k_lower` differ
+/// and makes the channel widen in turbulent regimes and narrow in quiet ones.
+///
 /// ## Links
 ///
 /// * <https://www.investopedia.com/terms/e/envelope.asp>
+/// * <https://www.investopedia.com/terms/k/keltnerchannel.asp>
 ///
 /// # 3 values
 ///
@@ -37,7 +46,7 @@ pub struct Envelopes<M: MovingAverageConstructor = MA> {
 	///
 	/// Period range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
 	pub ma: M,
-	/// Bound relative size. Default is `0.1`.
+	/// Bound relative size, used when `adaptive` is `false`. Default is `0.1`.
 	///
 	/// Range in (`0.0`; `+inf`).
 	pub k: ValueType,
@@ -45,6 +54,26 @@ pub struct Envelopes<M: MovingAverageConstructor = MA> {
 	pub source: Source,
 	/// Source2 value type for actual price. Default is [`Close`](crate::core::Source::Close).
 	pub source2: Source,
+	/// If `true`, bands are volatility-adaptive (Keltner-style): `upper`/`lower` are formed
+	/// by adding/subtracting a multiple of an [`ATR`](crate::methods::ATR) estimate from the
+	/// moving average instead of a fixed percentage of it.
+	///
+	/// Default is `false`.
+	pub adaptive: bool,
+	/// Upper band multiplier over the volatility estimate, used when `adaptive` is `true`.
+	/// Default is `2.0`.
+	///
+	/// Range in (`0.0`; `+inf`).
+	pub k_upper: ValueType,
+	/// Lower band multiplier over the volatility estimate, used when `adaptive` is `true`.
+	/// Default is `2.0`.
+	///
+	/// Range in (`0.0`; `+inf`).
+	pub k_lower: ValueType,
+	/// Volatility estimate's period, used when `adaptive` is `true`. Default is `14`.
+	///
+	/// Period range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub vol_period: PeriodType,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for Envelopes<M> {
@@ -64,12 +93,25 @@ impl<M: MovingAverageConstructor> IndicatorConfig for Envelopes<M> {
 			ma: cfg.ma.init(src)?, // method(cfg.method, cfg.period, src)?,
 			k_high: 1.0 + cfg.k,
 			k_low: 1.0 - cfg.k,
+			vol: if cfg.adaptive {
+				Some(ATR::new(cfg.vol_period, candle)?)
+			} else {
+				None
+			},
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.k > 0.0 && self.ma.ma_period() > 1
+		if self.ma.ma_period() <= 1 {
+			return false;
+		}
+
+		if self.adaptive {
+			self.k_upper > 0.0 && self.k_lower > 0.0 && self.vol_period > 1
+		} else {
+			self.k > 0.0
+		}
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -90,6 +132,22 @@ impl<M: MovingAverageConstructor> IndicatorConfig for Envelopes<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source2 = value,
 			},
+			"adaptive" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.adaptive = value,
+			},
+			"k_upper" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.k_upper = value,
+			},
+			"k_lower" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.k_lower = value,
+			},
+			"vol_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.vol_period = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -111,6 +169,10 @@ impl Default for Envelopes<MA> {
 			k: 0.1,
 			source: Source::Close,
 			source2: Source::Close,
+			adaptive: false,
+			k_upper: 2.0,
+			k_lower: 2.0,
+			vol_period: 14,
 		}
 	}
 }
@@ -123,6 +185,7 @@ pub struct EnvelopesInstance<M: MovingAverageConstructor = MA> {
 	ma: M::Instance,
 	k_high: ValueType,
 	k_low: ValueType,
+	vol: Option<ATR>,
 }
 
 impl<M: MovingAverageConstructor> IndicatorInstance for EnvelopesInstance<M> {
@@ -136,7 +199,12 @@ impl<M: MovingAverageConstructor> IndicatorInstance for EnvelopesInstance<M> {
 		let src = candle.source(self.cfg.source);
 		let v = self.ma.next(&src);
 
-		let (value1, value2) = (v * self.k_high, v * self.k_low);
+		let (value1, value2) = if let Some(vol) = &mut self.vol {
+			let vol = vol.next(candle);
+			(v + self.cfg.k_upper * vol, v - self.cfg.k_lower * vol)
+		} else {
+			(v * self.k_high, v * self.k_low)
+		};
 
 		let src2 = candle.source(self.cfg.source2);
 		// let signal = if src2 < value2 {
@@ -152,3 +220,120 @@ impl<M: MovingAverageConstructor> IndicatorInstance for EnvelopesInstance<M> {
 		IndicatorResult::new(&[value1, value2, src2], &[Action::from(signal)])
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(Debug, Clone, Copy, Default)]
+	struct TestCandle {
+		open: ValueType,
+		high: ValueType,
+		low: ValueType,
+		close: ValueType,
+		volume: ValueType,
+	}
+
+	impl OHLCV for TestCandle {
+		fn open(&self) -> ValueType {
+			self.open
+		}
+		fn high(&self) -> ValueType {
+			self.high
+		}
+		fn low(&self) -> ValueType {
+			self.low
+		}
+		fn close(&self) -> ValueType {
+			self.close
+		}
+		fn volume(&self) -> ValueType {
+			self.volume
+		}
+	}
+
+	// `close` stays constant across every candle so the moving average (and, in adaptive
+	// mode, the ATR true range) never has to warm up — only `open` varies, driving
+	// `source2`'s crossing checks independently of the bands themselves.
+	fn candle(open: ValueType) -> TestCandle {
+		TestCandle {
+			open,
+			high: 110.0,
+			low: 100.0,
+			close: 105.0,
+			volume: 1.0,
+		}
+	}
+
+	fn fixed_cfg() -> Envelopes {
+		Envelopes {
+			source: Source::Close,
+			source2: Source::Open,
+			..Envelopes::default()
+		}
+	}
+
+	#[test]
+	fn fixed_mode_bands_are_a_percentage_of_the_ma() {
+		let seed = candle(105.0);
+		let mut envelopes = fixed_cfg().init(&seed).unwrap();
+
+		let result = envelopes.next(&candle(105.0));
+		// `ma * (1.0 ± k)` with `k = 0.1`; `105.0 * 1.1` rounds to 115.50000000000001 in f64.
+		assert_eq!(result.values(), &[105.0 * (1.0 + 0.1), 105.0 * (1.0 - 0.1), 105.0]);
+		assert_eq!(result.signals()[0], Action::None);
+	}
+
+	#[test]
+	fn fixed_mode_signals_crossing_price_against_fixed_bands() {
+		let seed = candle(105.0);
+		let mut envelopes = fixed_cfg().init(&seed).unwrap();
+
+		let sell = envelopes.next(&candle(130.0));
+		assert!(matches!(sell.signals()[0], Action::Sell(_)));
+
+		let buy = envelopes.next(&candle(50.0));
+		assert!(matches!(buy.signals()[0], Action::Buy(_)));
+	}
+
+	#[test]
+	fn adaptive_mode_bands_track_atr_instead_of_a_percentage() {
+		let cfg = Envelopes {
+			source: Source::Close,
+			source2: Source::Open,
+			adaptive: true,
+			k_upper: 2.0,
+			k_lower: 2.0,
+			vol_period: 2,
+			..Envelopes::default()
+		};
+
+		let seed = candle(105.0);
+		let mut envelopes = cfg.init(&seed).unwrap();
+
+		// High=110, low=100, close=105 every candle: true range is a constant 10.0
+		// (no gaps, since `close` never moves), so `ma ± k * atr` is exactly computable.
+		let result = envelopes.next(&candle(105.0));
+		assert_eq!(result.values(), &[125.0, 85.0, 105.0]);
+		assert_eq!(result.signals()[0], Action::None);
+
+		let sell = envelopes.next(&candle(150.0));
+		assert!(matches!(sell.signals()[0], Action::Sell(_)));
+
+		let buy = envelopes.next(&candle(50.0));
+		assert!(matches!(buy.signals()[0], Action::Buy(_)));
+	}
+
+	#[test]
+	fn validate_rejects_non_positive_adaptive_multiplier() {
+		let cfg = Envelopes {
+			adaptive: true,
+			k_upper: 0.0,
+			k_lower: 2.0,
+			vol_period: 14,
+			..Envelopes::default()
+		};
+
+		assert!(!cfg.validate());
+	}
+}
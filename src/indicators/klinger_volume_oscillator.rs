@@ -53,6 +53,12 @@ pub struct KlingerVolumeOscillator<M: MovingAverageConstructor = MA> {
 	///
 	/// Period range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
 	pub signal: M,
+	/// If `true`, uses a simplified volume force (`sign(d) * volume`) instead of Klinger's
+	/// original Volume Force formula. Kept for backward compatibility with older versions
+	/// of this indicator.
+	///
+	/// Default is `false`.
+	pub simplified: bool,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for KlingerVolumeOscillator<M> {
@@ -66,6 +72,7 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KlingerVolumeOscillator<M>
 		}
 
 		let cfg = self;
+		let dm = candle.high() - candle.low();
 		Ok(Self::Instance {
 			ma1: cfg.ma1.init(0.)?,
 			ma2: cfg.ma2.init(0.)?,
@@ -73,6 +80,9 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KlingerVolumeOscillator<M>
 			cross1: Cross::default(),
 			cross2: Cross::default(),
 			last_tp: candle.tp(),
+			last_dm: dm,
+			last_cm: dm,
+			last_trend: 1,
 			cfg,
 		})
 	}
@@ -98,6 +108,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KlingerVolumeOscillator<M>
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.signal = value,
 			},
+			"simplified" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.simplified = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -118,6 +132,7 @@ impl Default for KlingerVolumeOscillator {
 			ma1: MA::EMA(34),
 			ma2: MA::EMA(55),
 			signal: MA::EMA(13),
+			simplified: false,
 		}
 	}
 }
@@ -133,6 +148,36 @@ pub struct KlingerVolumeOscillatorInstance<M: MovingAverageConstructor = MA> {
 	cross1: Cross,
 	cross2: Cross,
 	last_tp: ValueType,
+	last_dm: ValueType,
+	last_cm: ValueType,
+	last_trend: i8,
+}
+
+/// Computes Klinger's Volume Force for the current candle and the running cumulative
+/// measurement (`cm`) it feeds into.
+///
+/// `trend` is `+1`/`-1` depending on whether today's typical price rose or fell; `dm` is
+/// today's `high - low`. `cm` accumulates `dm` while `trend` persists and restarts from
+/// `last_dm` on a trend reversal. Returns `(vf, cm)` so the caller can persist `cm` as
+/// `last_cm` for the next candle.
+fn volume_force(
+	trend: i8,
+	dm: ValueType,
+	last_dm: ValueType,
+	last_cm: ValueType,
+	last_trend: i8,
+	volume: ValueType,
+) -> (ValueType, ValueType) {
+	let cm = if trend == last_trend {
+		last_cm + dm
+	} else {
+		last_dm + dm
+	};
+
+	let ratio = if cm == 0. { 0. } else { dm / cm };
+	let vf = volume * (2. * ratio - 1.).abs() * ValueType::from(trend) * 100.;
+
+	(vf, cm)
 }
 
 impl<M: MovingAverageConstructor> IndicatorInstance for KlingerVolumeOscillatorInstance<M> {
@@ -146,20 +191,31 @@ impl<M: MovingAverageConstructor> IndicatorInstance for KlingerVolumeOscillatorI
 		let tp = candle.tp();
 
 		let d = tp - self.last_tp;
+		let trend: i8 = if tp > self.last_tp { 1 } else { -1 };
 		self.last_tp = tp;
 
-		// let vol = if d > 0. {
-		// 	candle.volume()
-		// } else if d < 0. {
-		// 	-candle.volume()
-		// } else {
-		// 	0.
-		// };
+		let vf = if self.cfg.simplified {
+			sign(d) * candle.volume()
+		} else {
+			let dm = candle.high() - candle.low();
+			let (vf, cm) = volume_force(
+				trend,
+				dm,
+				self.last_dm,
+				self.last_cm,
+				self.last_trend,
+				candle.volume(),
+			);
 
-		let vol = sign(d) * candle.volume();
+			self.last_dm = dm;
+			self.last_cm = cm;
+			self.last_trend = trend;
 
-		let ma1: ValueType = self.ma1.next(&vol);
-		let ma2: ValueType = self.ma2.next(&vol);
+			vf
+		};
+
+		let ma1: ValueType = self.ma1.next(&vf);
+		let ma2: ValueType = self.ma2.next(&vf);
 		let ko = ma1 - ma2;
 
 		let ma3: ValueType = self.ma3.next(&ko);
@@ -170,3 +226,63 @@ impl<M: MovingAverageConstructor> IndicatorInstance for KlingerVolumeOscillatorI
 		IndicatorResult::new(&[ko, ma3], &[s1, s2])
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn uptrend_continuation_weights_vf_by_cm_ratio() {
+		// Same trend as last candle: `cm` accumulates `last_cm + dm`.
+		let (vf, cm) = volume_force(1, 5.0, 3.0, 3.0, 1, 10.0);
+
+		assert_eq!(cm, 8.0);
+		assert_eq!(vf, 250.0); // 10 * |2*(5/8) - 1| * 1 * 100
+	}
+
+	#[test]
+	fn downtrend_continuation_flips_the_sign() {
+		let (vf, cm) = volume_force(-1, 4.0, 2.0, 2.0, -1, 10.0);
+
+		assert_eq!(cm, 6.0);
+		// ratio = dm/cm = 4/6; vf = volume * |2*ratio - 1| * trend * 100, trend == -1.
+		assert!((vf - (-1000.0 / 3.0)).abs() < 1e-9);
+	}
+
+	#[test]
+	fn trend_reversal_restarts_cm_from_last_dm() {
+		// Trend flips: `cm` restarts from `last_dm + dm` instead of accumulating `last_cm`.
+		let (_, cm) = volume_force(-1, 4.0, 2.0, 100.0, 1, 10.0);
+
+		assert_eq!(cm, 6.0);
+	}
+
+	#[test]
+	fn zero_cm_is_guarded_instead_of_dividing() {
+		// Reversal where `last_dm` exactly cancels today's `dm`, making `cm == 0`.
+		let (vf, cm) = volume_force(-1, 4.0, -4.0, 0.0, 1, 2.0);
+
+		assert_eq!(cm, 0.0);
+		assert_eq!(vf, -200.0); // ratio guarded to 0.0: 2 * |2*0 - 1| * -1 * 100
+	}
+
+	#[test]
+	fn multi_candle_trend_persistence_accumulates_cm() {
+		let mut last_dm = 2.0;
+		let mut last_cm = 2.0;
+		let mut last_trend = 1;
+
+		let steps = [(1, 3.0), (1, 1.0), (-1, 2.0)];
+		let mut cms = Vec::new();
+
+		for (trend, dm) in steps {
+			let (_, cm) = volume_force(trend, dm, last_dm, last_cm, last_trend, 1.0);
+			cms.push(cm);
+			last_dm = dm;
+			last_cm = cm;
+			last_trend = trend;
+		}
+
+		assert_eq!(cms, vec![5.0, 6.0, 3.0]);
+	}
+}